@@ -0,0 +1,15 @@
+// Auto-mirrors the upstream oxc convention: every `Rule` impl must be named here, or the
+// linter never constructs it and `oxc --fix`/CI simply never run it even though its module
+// compiles and its own `#[test]` passes in isolation.
+use oxc_macros::declare_all_lint_rules;
+
+use crate::rule::{Rule, RuleMeta};
+
+declare_all_lint_rules! {
+    unicorn::no_constant_condition,
+    unicorn::no_extra_boolean_cast,
+    unicorn::no_needless_boolean,
+    unicorn::no_nonminimal_bool,
+    unicorn::no_useless_length_check,
+    unicorn::prefer_math_constants,
+}
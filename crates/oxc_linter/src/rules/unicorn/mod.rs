@@ -0,0 +1,13 @@
+pub mod no_constant_condition;
+pub mod no_extra_boolean_cast;
+pub mod no_needless_boolean;
+pub mod no_nonminimal_bool;
+pub mod no_useless_length_check;
+pub mod prefer_math_constants;
+
+pub use no_constant_condition::NoConstantCondition;
+pub use no_extra_boolean_cast::NoExtraBooleanCast;
+pub use no_needless_boolean::NoNeedlessBoolean;
+pub use no_nonminimal_bool::NoNonminimalBool;
+pub use no_useless_length_check::NoUselessLengthCheck;
+pub use prefer_math_constants::PreferMathConstants;
@@ -0,0 +1,150 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+
+use oxc_ast::{
+    ast::{Expression, LogicalExpression},
+    AstKind,
+};
+
+use crate::{context::LintContext, rule::Rule, utils::BoolCoerceExt, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoConstantConditionDiagnostic {
+    #[error("eslint-plugin-unicorn(no-constant-condition): Unexpected constant condition.")]
+    #[diagnostic(severity(warning), help("This condition is always {1}, so the branch it guards is dead or unconditional."))]
+    Condition(#[label] Span, &'static str),
+    #[error("eslint-plugin-unicorn(no-constant-condition): Unexpected constant operand in a logical expression.")]
+    #[diagnostic(severity(warning), help("In this boolean context the operand never changes the outcome and can be removed."))]
+    Operand(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoConstantCondition;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows conditions, built from literals, whose truthiness is known statically.
+    ///
+    /// ### Why is this bad?
+    /// A condition that is always truthy or always falsy makes the branch it guards either
+    /// dead code or unconditional, which is almost always a mistake.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// if ("") {
+    /// }
+    /// while (1) {
+    /// }
+    /// if (x && true) {
+    /// }
+    /// ```
+    NoConstantCondition,
+    correctness
+);
+
+/// Returns `true` when `node` (a `LogicalExpression`) is only ever consumed for its
+/// truthiness — the test of an `if`/`while`/`do-while`/`for`/ternary, the operand of `!`, or
+/// itself an operand of another such logical expression. Outside that, e.g. `const a = x ||
+/// false;`, the expression's *value* (not just its truthiness) escapes, so `x || false` is not
+/// interchangeable with `x` and dropping the `false` would be wrong.
+fn is_in_boolean_context<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return false;
+    };
+    match parent.kind() {
+        AstKind::ParenthesizedExpression(_) | AstKind::LogicalExpression(_) => {
+            is_in_boolean_context(parent, ctx)
+        }
+        AstKind::UnaryExpression(expr) => expr.operator == UnaryOperator::LogicalNot,
+        AstKind::IfStatement(stmt) => stmt.test.span() == node.kind().span(),
+        AstKind::WhileStatement(stmt) => stmt.test.span() == node.kind().span(),
+        AstKind::DoWhileStatement(stmt) => stmt.test.span() == node.kind().span(),
+        AstKind::ForStatement(stmt) => {
+            stmt.test.as_ref().is_some_and(|test| test.span() == node.kind().span())
+        }
+        AstKind::ConditionalExpression(expr) => expr.test.span() == node.kind().span(),
+        _ => false,
+    }
+}
+
+impl NoConstantCondition {
+    fn check_test(test: &Expression, ctx: &LintContext) {
+        if let Some(value) = ctx.simple_bool_coerce(test) {
+            let label = if value { "truthy" } else { "falsy" };
+            ctx.diagnostic(NoConstantConditionDiagnostic::Condition(test.span(), label));
+        }
+    }
+
+    fn check_operands(expr: &LogicalExpression, ctx: &LintContext) {
+        let redundant_value = match expr.operator {
+            LogicalOperator::And => true,
+            LogicalOperator::Or => false,
+            LogicalOperator::Coalesce => return,
+        };
+        for operand in [&expr.left, &expr.right] {
+            if ctx.simple_bool_coerce(operand) == Some(redundant_value) {
+                ctx.diagnostic(NoConstantConditionDiagnostic::Operand(operand.span()));
+            }
+        }
+    }
+}
+
+impl Rule for NoConstantCondition {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::IfStatement(stmt) => Self::check_test(&stmt.test, ctx),
+            AstKind::WhileStatement(stmt) => Self::check_test(&stmt.test, ctx),
+            AstKind::DoWhileStatement(stmt) => Self::check_test(&stmt.test, ctx),
+            AstKind::ConditionalExpression(expr) => Self::check_test(&expr.test, ctx),
+            AstKind::LogicalExpression(expr) => {
+                if is_in_boolean_context(node, ctx) {
+                    Self::check_operands(expr, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (x) {}",
+        "while (x) {}",
+        "do {} while (x);",
+        "x ? a : b",
+        "x && y",
+        "x || y",
+        "x ?? true",
+        "x && foo()",
+        // A constant *branch* of a ternary isn't a constant *condition* — only `x` is the test.
+        "x ? true : y",
+        // `x || false` is a value expression here, not a boolean test: for `x = 0` the two
+        // differ (`0` vs `false`), so this must NOT be flagged as a removable operand.
+        "const a = x && true;",
+        "const b = x || false;",
+    ];
+
+    let fail = vec![
+        "if (true) {}",
+        "if (false) {}",
+        "if (\"\") {}",
+        "if (0) {}",
+        "if (null) {}",
+        "while (1) {}",
+        "do {} while (true);",
+        "true ? x : y",
+        "if (x && true) {}",
+        "if (false || y) {}",
+        "!(x && true)",
+    ];
+
+    Tester::new_without_config(NoConstantCondition::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,234 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use oxc_ast::{
+    ast::{CallExpression, Expression},
+    AstKind,
+};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(no-extra-boolean-cast): Redundant boolean cast.")]
+#[diagnostic(
+    severity(warning),
+    help("This expression is already coerced to a boolean, the negation/cast is unnecessary.")
+)]
+struct NoExtraBooleanCastDiagnostic(#[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraBooleanCast;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallows unnecessary boolean casts (`!!x`, `Boolean(x)`, `!Boolean(x)`) in positions
+    /// that are already interpreted as booleans.
+    ///
+    /// ### Why is this bad?
+    /// In contexts such as an `if` test, a `while` test, or the operand of `!`, the value is
+    /// already converted to a boolean, so casting it again is redundant and only adds noise.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// if (!!foo) {
+    ///     // ...
+    /// }
+    ///
+    /// while (Boolean(foo)) {
+    ///     // ...
+    /// }
+    /// ```
+    NoExtraBooleanCast,
+    correctness,
+    fix
+);
+
+/// Returns `true` when `expr` sits in a position where it is already coerced to a boolean,
+/// i.e. the test of an `if`/`while`/`do-while`/`for`/ternary, or the operand of `!`.
+fn is_in_boolean_context<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return false;
+    };
+
+    match parent.kind() {
+        AstKind::ParenthesizedExpression(_) => is_in_boolean_context(parent, ctx),
+        AstKind::IfStatement(stmt) => stmt.test.span() == node.kind().span(),
+        AstKind::WhileStatement(stmt) => stmt.test.span() == node.kind().span(),
+        AstKind::DoWhileStatement(stmt) => stmt.test.span() == node.kind().span(),
+        AstKind::ForStatement(stmt) => {
+            stmt.test.as_ref().is_some_and(|test| test.span() == node.kind().span())
+        }
+        AstKind::ConditionalExpression(expr) => expr.test.span() == node.kind().span(),
+        AstKind::UnaryExpression(expr) => expr.operator == UnaryOperator::LogicalNot,
+        _ => false,
+    }
+}
+
+/// Returns `true` when `node`'s nearest non-parenthesized ancestor is itself a `!` that will
+/// fire its own diagnostic for the outer `!!` pair — i.e. that enclosing `!` sits in a boolean
+/// context. A `!!` pair nested directly inside such a negation (e.g. the middle `!` of
+/// `!!!foo` inside `if (!!!foo)`) is already covered by the diagnostic fired for that outer `!`,
+/// so reporting it too would overlap the same span. If the enclosing `!` is *not* in a boolean
+/// context (e.g. `var x = !!!foo;`), it never reports anything, so this pair must still be
+/// reported on its own.
+fn is_nested_in_not<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return false;
+    };
+
+    match parent.kind() {
+        AstKind::ParenthesizedExpression(_) => is_nested_in_not(parent, ctx),
+        AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+            is_in_boolean_context(parent, ctx)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` when `node` (a `Boolean(...)` call) sits directly under a `!` that is itself
+/// the inner half of a `!!` pair the first match arm will already report, e.g. the call in
+/// `!!Boolean(x)` — reporting it too would overlap that diagnostic/fix. A single `!` wrapping
+/// the call (`!Boolean(x)`) is not such a pair, so it's still handled by this arm.
+fn is_call_nested_in_reporting_not_pair<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return false;
+    };
+
+    match parent.kind() {
+        AstKind::ParenthesizedExpression(_) => is_call_nested_in_reporting_not_pair(parent, ctx),
+        AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+            is_nested_in_not(parent, ctx)
+        }
+        _ => false,
+    }
+}
+
+fn is_boolean_call(call: &CallExpression) -> bool {
+    !call.optional
+        && call.arguments.len() == 1
+        && call.arguments[0].as_expression().is_some()
+        && matches!(
+            call.callee.without_parenthesized(),
+            Expression::Identifier(ident) if ident.name == "Boolean"
+        )
+}
+
+/// Renders `expr` as the text to splice in place of the cast, parenthesizing it when its
+/// precedence is lower than the unary `!`/call it's replacing — otherwise something like
+/// `!Boolean(a || b)` would turn into `!a || b`, silently changing what the code does.
+fn render_replacement<'a>(expr: &Expression<'a>, source_text: &'a str) -> String {
+    let inner = expr.without_parenthesized();
+    let text = inner.span().source_text(source_text);
+    let needs_parens = matches!(
+        inner,
+        Expression::LogicalExpression(_)
+            | Expression::BinaryExpression(_)
+            | Expression::ConditionalExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::SequenceExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+    );
+    if needs_parens {
+        format!("({text})")
+    } else {
+        text.to_string()
+    }
+}
+
+impl Rule for NoExtraBooleanCast {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `!!x`
+            AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+                let Expression::UnaryExpression(inner) = expr.argument.without_parenthesized()
+                else {
+                    return;
+                };
+                if inner.operator != UnaryOperator::LogicalNot {
+                    return;
+                }
+                if is_nested_in_not(node, ctx) {
+                    return;
+                }
+                if !is_in_boolean_context(node, ctx) {
+                    return;
+                }
+                let argument_text = render_replacement(&inner.argument, ctx.source_text());
+                ctx.diagnostic_with_fix(NoExtraBooleanCastDiagnostic(expr.span), |fixer| {
+                    fixer.replace(expr.span, argument_text)
+                });
+            }
+            // `Boolean(x)` and `!Boolean(x)`
+            AstKind::CallExpression(call) => {
+                if !is_boolean_call(call) {
+                    return;
+                }
+                if is_call_nested_in_reporting_not_pair(node, ctx) {
+                    return;
+                }
+                if !is_in_boolean_context(node, ctx) {
+                    return;
+                }
+                let argument_text =
+                    render_replacement(call.arguments[0].as_expression().unwrap(), ctx.source_text());
+                ctx.diagnostic_with_fix(NoExtraBooleanCastDiagnostic(call.span), |fixer| {
+                    fixer.replace(call.span, argument_text)
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var x = !!foo;",
+        "var x = Boolean(foo);",
+        "var x = new Boolean(foo);",
+        "if (foo) {}",
+        "if (Boolean(foo, bar)) {}",
+        "if (Boolean()) {}",
+        "if (foo.Boolean(bar)) {}",
+        // The consequent of a ternary is not a boolean context (only the test is), so this
+        // cast is not provably redundant here.
+        "foo ? !!bar : baz",
+    ];
+
+    let fail = vec![
+        "if (!!foo) {}",
+        "while (!!foo) {}",
+        "do {} while (!!foo);",
+        "for (; !!foo; ) {}",
+        "!!foo ? bar : baz",
+        "if (Boolean(foo)) {}",
+        "while (Boolean(foo)) {}",
+        "if (!Boolean(foo)) {}",
+        "var x = !!!foo;",
+        "if (!!!foo) {}",
+        "if (!!Boolean(x)) {}",
+        "if (!Boolean(!!x)) {}",
+    ];
+
+    let fix = vec![
+        ("if (!!foo) {}", "if (foo) {}"),
+        ("if (Boolean(foo)) {}", "if (foo) {}"),
+        ("if (!Boolean(foo)) {}", "if (!foo) {}"),
+        ("if (!Boolean(a || b)) {}", "if (!(a || b)) {}"),
+        ("if (!!(a || b)) {}", "if ((a || b)) {}"),
+        ("if (!!!foo) {}", "if (!foo) {}"),
+        ("if (!!Boolean(x)) {}", "if (Boolean(x)) {}"),
+    ];
+
+    Tester::new_without_config(NoExtraBooleanCast::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
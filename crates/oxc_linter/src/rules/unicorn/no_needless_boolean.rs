@@ -0,0 +1,206 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+
+use oxc_ast::{
+    ast::{Expression, Statement},
+    AstKind,
+};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(no-needless-boolean): This branches on a condition only to return a boolean literal.")]
+#[diagnostic(severity(warning), help("The condition itself already is the boolean value; return it directly."))]
+struct NoNeedlessBooleanDiagnostic(#[label] Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNeedlessBoolean;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Detects `if`/`else` and ternaries whose only job is to turn a condition into the
+    /// matching boolean literal, and suggests returning (or using) the condition directly.
+    ///
+    /// ### Why is this bad?
+    /// `if (c) { return true; } else { return false; }` is a roundabout way of writing
+    /// `return c;`; the literal branches add nothing but an extra indirection.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function isEven(n) {
+    ///     if (n % 2 === 0) {
+    ///         return true;
+    ///     } else {
+    ///         return false;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```javascript
+    /// function isEven(n) {
+    ///     return n % 2 === 0;
+    /// }
+    /// ```
+    NoNeedlessBoolean,
+    pedantic,
+    fix
+);
+
+/// If `stmt` is (or unwraps to) `return <BooleanLiteral>;`, returns the literal's value.
+fn as_boolean_return(stmt: &Statement) -> Option<bool> {
+    match stmt {
+        Statement::ReturnStatement(ret) => {
+            let Expression::BooleanLiteral(lit) = ret.argument.as_ref()? else { return None };
+            Some(lit.value)
+        }
+        Statement::BlockStatement(block) => {
+            let [only] = block.body.as_slice() else { return None };
+            as_boolean_return(only)
+        }
+        _ => None,
+    }
+}
+
+/// Negates `test`'s source text, special-casing `===`/`!==` so `!(a === b)` reads as
+/// `a !== b` rather than the clunkier `!(a === b)`.
+fn negate(test: &Expression, source_text: &str) -> String {
+    let test = test.without_parenthesized();
+    if let Expression::BinaryExpression(bin) = test {
+        let flipped = match bin.operator {
+            BinaryOperator::StrictEquality => Some("!=="),
+            BinaryOperator::StrictInequality => Some("==="),
+            _ => None,
+        };
+        if let Some(op) = flipped {
+            let left = bin.left.span().source_text(source_text);
+            let right = bin.right.span().source_text(source_text);
+            return format!("{left} {op} {right}");
+        }
+    }
+
+    let text = test.span().source_text(source_text);
+    // Atomic operands (identifiers, member access, calls, literals, already-unary
+    // expressions, ...) read fine as `!c`; only wrap the ones `!` would otherwise
+    // reassociate with, such as a bare binary/logical/conditional expression.
+    let needs_parens = matches!(
+        test,
+        Expression::LogicalExpression(_)
+            | Expression::BinaryExpression(_)
+            | Expression::ConditionalExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::SequenceExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+    );
+    if needs_parens {
+        format!("!({text})")
+    } else {
+        format!("!{text}")
+    }
+}
+
+impl Rule for NoNeedlessBoolean {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::IfStatement(stmt) => {
+                let Some(alternate) = &stmt.alternate else { return };
+                let (Some(then_value), Some(else_value)) =
+                    (as_boolean_return(&stmt.consequent), as_boolean_return(alternate))
+                else {
+                    return;
+                };
+                if then_value == else_value {
+                    return;
+                }
+
+                let test_text = stmt.test.span().source_text(ctx.source_text());
+                let replacement = if then_value {
+                    format!("return {test_text};")
+                } else {
+                    format!("return {};", negate(&stmt.test, ctx.source_text()))
+                };
+
+                ctx.diagnostic_with_fix(NoNeedlessBooleanDiagnostic(stmt.span), |fixer| {
+                    fixer.replace(stmt.span, replacement)
+                });
+            }
+            AstKind::ConditionalExpression(expr) => {
+                let (Expression::BooleanLiteral(then_lit), Expression::BooleanLiteral(else_lit)) =
+                    (expr.consequent.without_parenthesized(), expr.alternate.without_parenthesized())
+                else {
+                    return;
+                };
+                if then_lit.value == else_lit.value {
+                    return;
+                }
+
+                let replacement = if then_lit.value {
+                    expr.test.span().source_text(ctx.source_text()).to_string()
+                } else {
+                    negate(&expr.test, ctx.source_text())
+                };
+
+                ctx.diagnostic_with_fix(NoNeedlessBooleanDiagnostic(expr.span), |fixer| {
+                    fixer.replace(expr.span, replacement)
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function f(c) { if (c) { return 1; } else { return 0; } }",
+        "function f(c) { if (c) { return true; } return 1; }",
+        "function f(c) { if (c) { return true; } else { return true; } }",
+        "c ? 1 : 0",
+        "c ? true : true",
+    ];
+
+    let fail = vec![
+        "function f(c) { if (c) { return true; } else { return false; } }",
+        "function f(c) { if (c) { return false; } else { return true; } }",
+        "function f(c) { if (c) return true; else return false; }",
+        "function f(a, b) { if (a === b) { return true; } else { return false; } }",
+        "function f(a, b) { if (a !== b) { return false; } else { return true; } }",
+        "c ? true : false",
+        "c ? false : true",
+        "function* f(c) { if (yield c) { return false; } else { return true; } }",
+    ];
+
+    let fix = vec![
+        (
+            "function f(c) { if (c) { return true; } else { return false; } }",
+            "function f(c) { return c; }",
+        ),
+        (
+            "function f(c) { if (c) { return false; } else { return true; } }",
+            "function f(c) { return !c; }",
+        ),
+        (
+            "function f(a, b) { if (a === b) { return true; } else { return false; } }",
+            "function f(a, b) { return a === b; }",
+        ),
+        (
+            "function f(a, b) { if (a === b) { return false; } else { return true; } }",
+            "function f(a, b) { return a !== b; }",
+        ),
+        ("c ? true : false", "c"),
+        ("c ? false : true", "!c"),
+        (
+            "function* f(c) { if (yield c) { return false; } else { return true; } }",
+            "function* f(c) { return !(yield c); }",
+        ),
+    ];
+
+    Tester::new_without_config(NoNeedlessBoolean::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,457 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+
+use oxc_ast::{
+    ast::{ChainElement, Expression, LogicalExpression},
+    AstKind,
+};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+/// Above this number of distinct terminals the truth table becomes too expensive to enumerate.
+const MAX_TERMINALS: usize = 8;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(no-nonminimal-bool): This boolean expression can be simplified.")]
+#[diagnostic(severity(warning), help("This is equivalent to `{1}`."))]
+struct NoNonminimalBoolDiagnostic(#[label] Span, String);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNonminimalBool;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Checks boolean expressions built out of `&&`, `||` and `!` for ones that can be
+    /// rewritten with fewer terminal occurrences while remaining provably equivalent.
+    ///
+    /// ### Why is this bad?
+    /// A longer boolean expression than necessary is harder to read and reason about.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// if (a && true) {
+    /// }
+    /// if (a || a) {
+    /// }
+    /// if (a && !a) {
+    /// }
+    /// ```
+    NoNonminimalBool,
+    correctness,
+    fix
+);
+
+#[derive(Debug, Clone)]
+enum BoolExpr {
+    True,
+    False,
+    Term(usize),
+    Not(Box<BoolExpr>),
+    And(Vec<BoolExpr>),
+    Or(Vec<BoolExpr>),
+}
+
+/// Structural, span-ignoring equality between two expressions. Falls back to comparing the
+/// trimmed source text for expression kinds we don't special-case, which is still sound for
+/// our purposes: two pieces of source text that match verbatim denote the same terminal.
+fn spanless_eq<'a>(a: &Expression<'a>, b: &Expression<'a>, source_text: &'a str) -> bool {
+    match (a.without_parenthesized(), b.without_parenthesized()) {
+        (Expression::Identifier(a), Expression::Identifier(b)) => a.name == b.name,
+        (Expression::BooleanLiteral(a), Expression::BooleanLiteral(b)) => a.value == b.value,
+        (Expression::NumericLiteral(a), Expression::NumericLiteral(b)) => a.value == b.value,
+        (Expression::StringLiteral(a), Expression::StringLiteral(b)) => a.value == b.value,
+        (Expression::NullLiteral(_), Expression::NullLiteral(_)) => true,
+        (Expression::UnaryExpression(a), Expression::UnaryExpression(b)) => {
+            a.operator == b.operator && spanless_eq(&a.argument, &b.argument, source_text)
+        }
+        (Expression::BinaryExpression(a), Expression::BinaryExpression(b)) => {
+            a.operator == b.operator
+                && spanless_eq(&a.left, &b.left, source_text)
+                && spanless_eq(&a.right, &b.right, source_text)
+        }
+        (a, b) => a.span().source_text(source_text) == b.span().source_text(source_text),
+    }
+}
+
+/// Bails out the whole rewrite when a terminal can execute side effects: short-circuiting
+/// means reordering or dropping a terminal would change how many times those side effects run.
+fn contains_side_effect(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::CallExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::TaggedTemplateExpression(_) => true,
+        Expression::UnaryExpression(e) => contains_side_effect(&e.argument),
+        Expression::BinaryExpression(e) => {
+            contains_side_effect(&e.left) || contains_side_effect(&e.right)
+        }
+        Expression::LogicalExpression(e) => {
+            contains_side_effect(&e.left) || contains_side_effect(&e.right)
+        }
+        Expression::ConditionalExpression(e) => {
+            contains_side_effect(&e.test)
+                || contains_side_effect(&e.consequent)
+                || contains_side_effect(&e.alternate)
+        }
+        Expression::SequenceExpression(e) => e.expressions.iter().any(contains_side_effect),
+        Expression::StaticMemberExpression(e) => contains_side_effect(&e.object),
+        Expression::ComputedMemberExpression(e) => {
+            contains_side_effect(&e.object) || contains_side_effect(&e.expression)
+        }
+        Expression::TemplateLiteral(e) => e.expressions.iter().any(contains_side_effect),
+        Expression::ChainExpression(e) => contains_side_effect_chain(&e.expression),
+        _ => false,
+    }
+}
+
+/// Same as `contains_side_effect` but for the element of a `ChainExpression` (`a?.()`, `a?.b`,
+/// `a?.[b]`), which optional chaining wraps in its own node kind rather than reusing `Expression`.
+fn contains_side_effect_chain(element: &ChainElement) -> bool {
+    match element {
+        ChainElement::CallExpression(_) => true,
+        ChainElement::StaticMemberExpression(e) => contains_side_effect(&e.object),
+        ChainElement::ComputedMemberExpression(e) => {
+            contains_side_effect(&e.object) || contains_side_effect(&e.expression)
+        }
+        ChainElement::PrivateFieldExpression(e) => contains_side_effect(&e.object),
+        _ => true,
+    }
+}
+
+struct Builder<'a> {
+    terminals: Vec<&'a Expression<'a>>,
+    source_text: &'a str,
+}
+
+impl<'a> Builder<'a> {
+    fn term_index(&mut self, expr: &'a Expression<'a>) -> Option<usize> {
+        if let Some(i) =
+            self.terminals.iter().position(|t| spanless_eq(t, expr, self.source_text))
+        {
+            return Some(i);
+        }
+        if self.terminals.len() >= MAX_TERMINALS {
+            return None;
+        }
+        self.terminals.push(expr);
+        Some(self.terminals.len() - 1)
+    }
+
+    fn build_logical(&mut self, log: &'a LogicalExpression<'a>) -> Option<BoolExpr> {
+        let left = self.build(&log.left)?;
+        let right = self.build(&log.right)?;
+        Some(match log.operator {
+            LogicalOperator::And => BoolExpr::And(vec![left, right]),
+            LogicalOperator::Or => BoolExpr::Or(vec![left, right]),
+            LogicalOperator::Coalesce => return None,
+        })
+    }
+
+    fn build(&mut self, expr: &'a Expression<'a>) -> Option<BoolExpr> {
+        match expr.without_parenthesized() {
+            Expression::LogicalExpression(log) => self.build_logical(log),
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                Some(BoolExpr::Not(Box::new(self.build(&unary.argument)?)))
+            }
+            Expression::BooleanLiteral(lit) => {
+                Some(if lit.value { BoolExpr::True } else { BoolExpr::False })
+            }
+            _ => {
+                if contains_side_effect(expr) {
+                    return None;
+                }
+                let inner = expr.without_parenthesized();
+                Some(BoolExpr::Term(self.term_index(inner)?))
+            }
+        }
+    }
+}
+
+fn bool_expr_eq(a: &BoolExpr, b: &BoolExpr) -> bool {
+    match (a, b) {
+        (BoolExpr::True, BoolExpr::True) | (BoolExpr::False, BoolExpr::False) => true,
+        (BoolExpr::Term(a), BoolExpr::Term(b)) => a == b,
+        (BoolExpr::Not(a), BoolExpr::Not(b)) => bool_expr_eq(a, b),
+        (BoolExpr::And(a), BoolExpr::And(b)) | (BoolExpr::Or(a), BoolExpr::Or(b)) => {
+            a.len() == b.len()
+                && a.iter().all(|x| b.iter().any(|y| bool_expr_eq(x, y)))
+                && b.iter().all(|y| a.iter().any(|x| bool_expr_eq(x, y)))
+        }
+        _ => false,
+    }
+}
+
+fn is_negation_of(a: &BoolExpr, b: &BoolExpr) -> bool {
+    matches!(a, BoolExpr::Not(inner) if bool_expr_eq(inner, b))
+        || matches!(b, BoolExpr::Not(inner) if bool_expr_eq(inner, a))
+}
+
+/// Flattens nested chains of the same operator and folds constants, duplicates and
+/// complementary pairs (`a && !a`, `a || a`, `a && true`, ...).
+fn simplify_chain(items: Vec<BoolExpr>, is_and: bool) -> BoolExpr {
+    let mut flat = Vec::with_capacity(items.len());
+    for item in items {
+        match item {
+            BoolExpr::And(xs) if is_and => flat.extend(xs),
+            BoolExpr::Or(xs) if !is_and => flat.extend(xs),
+            other => flat.push(other),
+        }
+    }
+
+    let absorbing = if is_and { BoolExpr::False } else { BoolExpr::True };
+    let identity = if is_and { BoolExpr::True } else { BoolExpr::False };
+
+    if flat.iter().any(|x| bool_expr_eq(x, &absorbing)) {
+        return absorbing;
+    }
+    flat.retain(|x| !bool_expr_eq(x, &identity));
+
+    let mut deduped: Vec<BoolExpr> = Vec::with_capacity(flat.len());
+    for item in flat {
+        if deduped.iter().any(|x| bool_expr_eq(x, &item)) {
+            continue;
+        }
+        if deduped.iter().any(|x| is_negation_of(x, &item)) {
+            return absorbing;
+        }
+        deduped.push(item);
+    }
+
+    match deduped.len() {
+        0 => identity,
+        1 => deduped.into_iter().next().unwrap(),
+        _ if is_and => BoolExpr::And(deduped),
+        _ => BoolExpr::Or(deduped),
+    }
+}
+
+fn simplify(expr: BoolExpr) -> BoolExpr {
+    match expr {
+        BoolExpr::Not(inner) => match simplify(*inner) {
+            BoolExpr::True => BoolExpr::False,
+            BoolExpr::False => BoolExpr::True,
+            BoolExpr::Not(x) => *x,
+            // De Morgan: push the negation inward so complementary pairs can cancel.
+            BoolExpr::And(xs) => {
+                simplify(BoolExpr::Or(xs.into_iter().map(|x| BoolExpr::Not(Box::new(x))).collect()))
+            }
+            BoolExpr::Or(xs) => {
+                simplify(BoolExpr::And(xs.into_iter().map(|x| BoolExpr::Not(Box::new(x))).collect()))
+            }
+            other => BoolExpr::Not(Box::new(other)),
+        },
+        BoolExpr::And(xs) => simplify_chain(xs.into_iter().map(simplify).collect(), true),
+        BoolExpr::Or(xs) => simplify_chain(xs.into_iter().map(simplify).collect(), false),
+        other => other,
+    }
+}
+
+/// Counts every leaf (`Term`, `true`, `false`) and every `!` wrapping one, so that dropping a
+/// redundant `true`/`false` operand (`a && true` -> `a`) or a redundant double negation
+/// (`!!a` -> `a`) both count as a reduction, not just dropping a distinct terminal.
+fn term_count(expr: &BoolExpr) -> usize {
+    match expr {
+        BoolExpr::True | BoolExpr::False | BoolExpr::Term(_) => 1,
+        BoolExpr::Not(inner) => 1 + term_count(inner),
+        BoolExpr::And(xs) | BoolExpr::Or(xs) => xs.iter().map(term_count).sum(),
+    }
+}
+
+fn eval(expr: &BoolExpr, assignment: &[bool]) -> bool {
+    match expr {
+        BoolExpr::True => true,
+        BoolExpr::False => false,
+        BoolExpr::Term(i) => assignment[*i],
+        BoolExpr::Not(inner) => !eval(inner, assignment),
+        BoolExpr::And(xs) => xs.iter().all(|x| eval(x, assignment)),
+        BoolExpr::Or(xs) => xs.iter().any(|x| eval(x, assignment)),
+    }
+}
+
+fn truth_table(expr: &BoolExpr, n: usize) -> Vec<bool> {
+    (0..(1usize << n))
+        .map(|mask| {
+            let assignment: Vec<bool> = (0..n).map(|i| mask & (1 << i) != 0).collect();
+            eval(expr, &assignment)
+        })
+        .collect()
+}
+
+/// Whether a `Term`'s underlying expression needs parenthesizing when it's negated (`!term`) or
+/// used as an operand of an `&&`/`||` chain. A `Term` can be any non-atomic expression — the
+/// builder stores its raw source text without restricting its shape — so e.g. `!(a === b)`
+/// rendered without parens would become `!a === b`, which parses as `(!a) === b`. Same
+/// precedence check as `no_extra_boolean_cast::render_replacement` and
+/// `no_needless_boolean::negate`.
+fn term_needs_parens(expr: &Expression) -> bool {
+    matches!(
+        expr.without_parenthesized(),
+        Expression::LogicalExpression(_)
+            | Expression::BinaryExpression(_)
+            | Expression::ConditionalExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::SequenceExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+    )
+}
+
+fn render(expr: &BoolExpr, terminal_texts: &[&str], terminal_needs_parens: &[bool]) -> String {
+    match expr {
+        BoolExpr::True => "true".to_string(),
+        BoolExpr::False => "false".to_string(),
+        BoolExpr::Term(i) => terminal_texts[*i].to_string(),
+        BoolExpr::Not(inner) => match inner.as_ref() {
+            BoolExpr::And(_) | BoolExpr::Or(_) => {
+                format!("!({})", render(inner, terminal_texts, terminal_needs_parens))
+            }
+            BoolExpr::Term(i) if terminal_needs_parens[*i] => {
+                format!("!({})", render(inner, terminal_texts, terminal_needs_parens))
+            }
+            _ => format!("!{}", render(inner, terminal_texts, terminal_needs_parens)),
+        },
+        BoolExpr::And(xs) => xs
+            .iter()
+            .map(|x| match x {
+                BoolExpr::Or(_) => {
+                    format!("({})", render(x, terminal_texts, terminal_needs_parens))
+                }
+                BoolExpr::Term(i) if terminal_needs_parens[*i] => format!("({})", terminal_texts[*i]),
+                _ => render(x, terminal_texts, terminal_needs_parens),
+            })
+            .collect::<Vec<_>>()
+            .join(" && "),
+        BoolExpr::Or(xs) => xs
+            .iter()
+            .map(|x| match x {
+                BoolExpr::Term(i) if terminal_needs_parens[*i] => format!("({})", terminal_texts[*i]),
+                _ => render(x, terminal_texts, terminal_needs_parens),
+            })
+            .collect::<Vec<_>>()
+            .join(" || "),
+    }
+}
+
+/// A `LogicalExpression` is the top of its boolean tree — the point from which `run` should
+/// start analysis — unless some enclosing `&&`/`||` will already cover it when *that* node is
+/// visited. A wrapping `!` doesn't dispatch on its own (`run` only matches `LogicalExpression`),
+/// so we walk up through it exactly like we do parens: if nothing above the chain of `!`s and
+/// parens turns out to be a further `&&`/`||`, this node is still the right place to start.
+fn is_top_of_bool_tree(node: &AstNode, ctx: &LintContext) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return true;
+    };
+    match parent.kind() {
+        AstKind::LogicalExpression(log)
+            if matches!(log.operator, LogicalOperator::And | LogicalOperator::Or) =>
+        {
+            false
+        }
+        AstKind::UnaryExpression(u) if u.operator == UnaryOperator::LogicalNot => {
+            is_top_of_bool_tree(parent, ctx)
+        }
+        AstKind::ParenthesizedExpression(_) => is_top_of_bool_tree(parent, ctx),
+        _ => true,
+    }
+}
+
+impl Rule for NoNonminimalBool {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::LogicalExpression(log_expr) = node.kind() else { return };
+        if !matches!(log_expr.operator, LogicalOperator::And | LogicalOperator::Or) {
+            return;
+        }
+        if !is_top_of_bool_tree(node, ctx) {
+            return;
+        }
+
+        let mut builder = Builder { terminals: Vec::new(), source_text: ctx.source_text() };
+        let Some(original) = builder.build_logical(log_expr) else {
+            return;
+        };
+        let n = builder.terminals.len();
+        if n == 0 || n > MAX_TERMINALS {
+            return;
+        }
+
+        let simplified = simplify(original.clone());
+        let original_count = term_count(&original);
+        let simplified_count = term_count(&simplified);
+        if simplified_count >= original_count {
+            return;
+        }
+
+        if truth_table(&original, n) != truth_table(&simplified, n) {
+            return;
+        }
+
+        let terminal_texts: Vec<&str> =
+            builder.terminals.iter().map(|t| t.span().source_text(ctx.source_text())).collect();
+        let terminal_needs_parens: Vec<bool> =
+            builder.terminals.iter().map(|t| term_needs_parens(t)).collect();
+        let suggestion = render(&simplified, &terminal_texts, &terminal_needs_parens);
+        let span = log_expr.span;
+
+        ctx.diagnostic_with_fix(NoNonminimalBoolDiagnostic(span, suggestion.clone()), |fixer| {
+            fixer.replace(span, suggestion)
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "a && b",
+        "a || b",
+        "a && (b || c)",
+        "foo() && foo()",
+        "a && foo()",
+        "(a = true) && a",
+        "a?.() && a?.()",
+        "`${a()}` && `${a()}`",
+    ];
+
+    let fail = vec![
+        "a && true",
+        "true && a",
+        "a || false",
+        "false || a",
+        "a && false",
+        "a || true",
+        "a || a",
+        "a && a",
+        "a && !a",
+        "a || !a",
+        "!!a && b",
+        "!(a && !a);",
+        "!(a === b) && !(a === b)",
+    ];
+
+    let fix = vec![
+        ("a && true", "a"),
+        ("true && a", "a"),
+        ("a || false", "a"),
+        ("false || a", "a"),
+        ("a && false", "false"),
+        ("a || true", "true"),
+        ("a || a", "a"),
+        ("a && a", "a"),
+        ("a && !a", "false"),
+        ("a || !a", "true"),
+        ("!!a && b", "a && b"),
+        ("!(a && !a);", "!(false);"),
+        ("!(a === b) && !(a === b)", "!(a === b)"),
+    ];
+
+    Tester::new_without_config(NoNonminimalBool::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
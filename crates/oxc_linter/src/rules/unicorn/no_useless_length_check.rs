@@ -33,6 +33,14 @@ enum NoUselessLengthCheckDiagnostic {
     Every(#[label] Span),
 }
 
+impl NoUselessLengthCheckDiagnostic {
+    fn span(&self) -> Span {
+        match self {
+            Self::Some(span) | Self::Every(span) => *span,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct NoUselessLengthCheck;
 
@@ -54,7 +62,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoUselessLengthCheck,
-    correctness
+    correctness,
+    fix
 );
 
 struct ConditionDTO<T: ToString> {
@@ -69,6 +78,10 @@ fn get_static_member_property_name<'a>(expr: Option<&'a MemberExpression<'a>>) -
     }
 }
 
+// Deliberately matches the exact raw text `0` rather than going through `simple_bool_coerce`:
+// this rule only fires on a literal `=== 0`/`> 0` spelling, not on anything that merely
+// coerces to zero-ish (`0x0`, `0.` stay untouched below), so the shared truthiness helper
+// doesn't apply here.
 fn is_useless_check<'a>(
     left: &'a Expression<'a>,
     right: &'a Expression<'a>,
@@ -171,11 +184,36 @@ impl Rule for NoUselessLengthCheck {
                 return;
             }
             let flat_expr = flat_logical_expression(log_expr);
+            // Tracks operands already paired off with a neighbor so a chain like
+            // `array.length === 0 || array.every(Boolean) || array.length === 0` doesn't match
+            // the middle operand against both sides and fire two diagnostics (with two
+            // conflicting fixes) for what the fixer can only collapse once.
+            let mut consumed = vec![false; flat_expr.len()];
             for i in 0..flat_expr.len() - 1 {
+                if consumed[i] || consumed[i + 1] {
+                    continue;
+                }
                 if let Some(diag) =
                     is_useless_check(flat_expr[i], flat_expr[i + 1], log_expr.operator)
                 {
-                    ctx.diagnostic(diag);
+                    consumed[i] = true;
+                    consumed[i + 1] = true;
+                    let redundant_span = diag.span();
+                    let operator_text = match log_expr.operator {
+                        LogicalOperator::Or => " || ",
+                        LogicalOperator::And => " && ",
+                        LogicalOperator::Coalesce => return,
+                    };
+                    ctx.diagnostic_with_fix(diag, |fixer| {
+                        let remaining_text = flat_expr
+                            .iter()
+                            .map(|expr| expr.without_parenthesized())
+                            .filter(|expr| expr.span() != redundant_span)
+                            .map(|expr| expr.span().source_text(ctx.source_text()))
+                            .collect::<Vec<_>>()
+                            .join(operator_text);
+                        fixer.replace(log_expr.span, remaining_text)
+                    });
                 }
             }
         };
@@ -297,7 +335,25 @@ fn test() {
         "array.length > 0 && (array.some(Boolean) && foo)",
         "array.every(Boolean) || array.length === 0 || array.every(Boolean)",
         "array.length === 0 || array.every(Boolean) || array.length === 0",
+        "array.length === 0 || array.every(Boolean) || array.length === 0 || array.every(Boolean)",
+    ];
+
+    let fix = vec![
+        ("array.length === 0 || array.every(Boolean)", "array.every(Boolean)"),
+        ("array.length > 0 && array.some(Boolean)", "array.some(Boolean)"),
+        ("array.length !== 0 && array.some(Boolean)", "array.some(Boolean)"),
+        ("array.every(Boolean) || array.length === 0", "array.every(Boolean)"),
+        (
+            "foo || array.length === 0 || array.every(Boolean)",
+            "foo || array.every(Boolean)",
+        ),
+        (
+            "(foo && array.length > 0) && array.some(Boolean)",
+            "foo && array.some(Boolean)",
+        ),
     ];
 
-    Tester::new_without_config(NoUselessLengthCheck::NAME, pass, fail).test_and_snapshot();
+    Tester::new_without_config(NoUselessLengthCheck::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
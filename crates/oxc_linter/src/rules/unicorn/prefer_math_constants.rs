@@ -0,0 +1,146 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use oxc_ast::AstKind;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+/// Hand-typed approximations of these `Math` constants are easy to mistype and harder to read
+/// than the named constant they stand in for. Mirrors clippy's `approx_const` table.
+const CONSTANTS: &[(f64, &str)] = &[
+    (std::f64::consts::PI, "Math.PI"),
+    (std::f64::consts::E, "Math.E"),
+    (std::f64::consts::SQRT_2, "Math.SQRT2"),
+    (std::f64::consts::FRAC_1_SQRT_2, "Math.SQRT1_2"),
+    (std::f64::consts::LN_2, "Math.LN2"),
+    (std::f64::consts::LN_10, "Math.LN10"),
+    (std::f64::consts::LOG2_E, "Math.LOG2E"),
+    (std::f64::consts::LOG10_E, "Math.LOG10E"),
+];
+
+/// A literal needs at least this many fractional digits to plausibly be a hand-typed
+/// approximation rather than a deliberately short number like `3.14`.
+const MIN_FRACTIONAL_DIGITS: usize = 3;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-unicorn(prefer-math-constants): This looks like an approximation of `{1}`.")]
+#[diagnostic(severity(warning), help("Use `{1}` instead of a hand-typed approximation."))]
+struct PreferMathConstantsDiagnostic(#[label] Span, &'static str);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferMathConstants;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Warns when a numeric literal is a hand-typed approximation of a standard `Math`
+    /// constant and suggests the named constant instead.
+    ///
+    /// ### Why is this bad?
+    /// `Math.PI`, `Math.SQRT2` and friends are exact and self-documenting; a literal like
+    /// `3.14159` is both harder to read and one fat-fingered digit away from being wrong.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const area = 3.14159 * r * r;
+    /// ```
+    /// Use instead:
+    /// ```javascript
+    /// const area = Math.PI * r * r;
+    /// ```
+    PreferMathConstants,
+    pedantic,
+    fix
+);
+
+/// The number of digits written after the decimal point in `raw`, or `None` if it has none.
+///
+/// Literals with an exponent (`3.14159e1`) are rejected outright rather than having the
+/// exponent stripped: the mantissa alone no longer reflects the literal's actual value, so
+/// comparing it against a constant would match `31.4159` against `Math.PI` and silently change
+/// what the fixer computes.
+fn fractional_digits(raw: &str) -> Option<usize> {
+    if raw.contains(['e', 'E']) {
+        return None;
+    }
+    let (_, fraction) = raw.split_once('.')?;
+    Some(fraction.len())
+}
+
+/// Formats `value`'s fractional part truncated — never rounded — to exactly `digits` digits,
+/// matching how a hand-typed approximation is produced (someone copies digits, they don't
+/// round the trailing one up).
+fn truncated_fraction(value: f64, digits: usize) -> String {
+    let scale = 10f64.powi(i32::try_from(digits).unwrap_or(i32::MAX));
+    let scaled = (value.fract().abs() * scale).trunc() as u64;
+    format!("{scaled:0digits$}")
+}
+
+fn approximated_constant(raw: &str, digits: usize) -> Option<&'static str> {
+    if digits < MIN_FRACTIONAL_DIGITS {
+        return None;
+    }
+    let (int_part, frac_part) = raw.split_once('.')?;
+    // `.70710` is a valid, equivalent spelling of `0.70710` with the leading zero elided.
+    let lit_int: i64 = if int_part.is_empty() { 0 } else { int_part.parse().ok()? };
+
+    CONSTANTS.iter().find_map(|(constant, name)| {
+        (constant.trunc() as i64 == lit_int && truncated_fraction(*constant, digits) == frac_part)
+            .then_some(*name)
+    })
+}
+
+impl Rule for PreferMathConstants {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::NumericLiteral(lit) = node.kind() else { return };
+        let Some(digits) = fractional_digits(lit.raw) else { return };
+        let Some(name) = approximated_constant(lit.raw, digits) else { return };
+
+        ctx.diagnostic_with_fix(PreferMathConstantsDiagnostic(lit.span, name), |fixer| {
+            fixer.replace(lit.span, name)
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const x = 3.14;",
+        "const x = 3;",
+        "const x = Math.PI;",
+        "const x = 1.5;",
+        "const x = 3.14158;",
+        "const x = 3.14159e1;",
+        "const x = 3.14159e0;",
+    ];
+
+    let fail = vec![
+        "const x = 3.14159;",
+        "const x = 2.71828;",
+        "const x = 1.41421;",
+        "const x = 0.70710;",
+        "const x = 0.69314;",
+        "const x = 2.30258;",
+        "const x = 1.44269;",
+        "const x = 0.43429;",
+        "const x = .70710;",
+    ];
+
+    let fix = vec![
+        ("const x = 3.14159;", "const x = Math.PI;"),
+        ("const x = 0.70710;", "const x = Math.SQRT1_2;"),
+        ("const x = .70710;", "const x = Math.SQRT1_2;"),
+        ("const x = 0.69314;", "const x = Math.LN2;"),
+        ("const x = 2.30258;", "const x = Math.LN10;"),
+        ("const x = 1.44269;", "const x = Math.LOG2E;"),
+    ];
+
+    Tester::new_without_config(PreferMathConstants::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,50 @@
+//! Small, dependency-free helpers shared by several lint rules.
+
+use oxc_ast::ast::Expression;
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::context::LintContext;
+
+/// Returns the statically-known truthiness of `expr`, or `None` if it can't be determined
+/// without evaluating the program (e.g. it reads a variable or calls a function).
+///
+/// Mirrors the set of "obviously constant" expressions eslint's `no-constant-condition`
+/// and rslint's equivalent utility recognize: boolean/numeric/string/null/undefined literals,
+/// `undefined`, and literals that are always truthy (regex, array, object, function literals).
+/// A plain `=` assignment coerces to the truthiness of its right-hand side.
+pub fn simple_bool_coerce(expr: &Expression) -> Option<bool> {
+    match expr.without_parenthesized() {
+        Expression::BooleanLiteral(lit) => Some(lit.value),
+        Expression::NumericLiteral(lit) => Some(lit.value != 0.0 && !lit.value.is_nan()),
+        Expression::StringLiteral(lit) => Some(!lit.value.is_empty()),
+        Expression::NullLiteral(_) => Some(false),
+        Expression::Identifier(ident) if ident.name == "undefined" => Some(false),
+        Expression::RegExpLiteral(_)
+        | Expression::ArrayExpression(_)
+        | Expression::ObjectExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ClassExpression(_) => Some(true),
+        Expression::AssignmentExpression(assign) if assign.operator == AssignmentOperator::Assign => {
+            simple_bool_coerce(&assign.right)
+        }
+        _ => None,
+    }
+}
+
+/// Gives lint rules `ctx.simple_bool_coerce(expr)` instead of importing the free function
+/// directly, matching how other per-expression helpers are reached off `LintContext`.
+///
+/// Note this is about *runtime truthiness*: it answers "does this value coerce to `true` or
+/// `false`?". It is not a substitute for a rule that needs to recognize one specific literal
+/// spelling (e.g. `no-useless-length-check` matching the raw text `0` but not `0x0`/`0.`) —
+/// those checks are deliberately stricter than truthiness and keep their own logic.
+pub trait BoolCoerceExt<'a> {
+    fn simple_bool_coerce(&self, expr: &Expression<'a>) -> Option<bool>;
+}
+
+impl<'a> BoolCoerceExt<'a> for LintContext<'a> {
+    fn simple_bool_coerce(&self, expr: &Expression<'a>) -> Option<bool> {
+        simple_bool_coerce(expr)
+    }
+}